@@ -1,29 +1,122 @@
-use num::Zero;
+use num::{CheckedAdd, Zero};
 use std::{
     cmp::Ordering::{Equal, Greater, Less},
     convert::TryFrom,
+    hash::{Hash, Hasher},
     mem,
-    ops::{Deref, Range},
+    ops::{Deref, Range, RangeInclusive},
 };
 
 const START_AFTER_END_ERR: &str =
     "The start of the given range is greater than the end of the given range";
 const END_GREATER_THAN_ZERO: &str = "The end of the given range is greater than zero";
 const START_LESS_THAN_ZERO: &str = "The start of the given range is less than zero";
+const START_LESS_THAN_END_ERR: &str =
+    "The start of the given range is less than the end of the given range";
+const END_LESS_THAN_ZERO: &str = "The end of the given range is less than zero";
+const START_GREATER_THAN_ZERO: &str = "The start of the given range is greater than zero";
 
 pub trait RangeExt {
+    type Item;
     /// `self` covers `other`.
     fn covers(&self, other: &Self) -> bool;
     /// `self` intersects `other`.
     fn intersects(&self, other: &Self) -> bool;
+    /// `point` lies in `[start, end)`.
+    fn contains(&self, point: &Self::Item) -> bool;
+    /// The overlapping sub-range of `self` and `other`, or `None` if they don't intersect.
+    fn intersection(&self, other: &Self) -> Option<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone;
+    /// Grows `self`'s bounds to reach `point`. Since `contains` is half-open, a `point` equal
+    /// to the new `end` is not itself contained.
+    fn grow_to_contain(&mut self, point: &Self::Item)
+    where
+        Self::Item: Clone;
+    /// The smallest range covering both `self` and `other`.
+    fn hull(&self, other: &Self) -> Self
+    where
+        Self: Sized,
+        Self::Item: Clone;
+    /// Splits `self` into `[start, mid)` and `[mid, end)`, or `None` if `mid` does not lie
+    /// strictly inside `self`.
+    fn split_at(&self, mid: &Self::Item) -> Option<(Self, Self)>
+    where
+        Self: Sized,
+        Self::Item: Clone;
 }
 
 impl<T: Ord> RangeExt for Range<T> {
+    type Item = T;
     fn covers(&self, other: &Self) -> bool {
         self.start <= other.start && self.end >= other.end
     }
     fn intersects(&self, other: &Self) -> bool {
-        self.start <= other.end && self.end <= other.start
+        self.start < other.end && other.start < self.end
+    }
+    fn contains(&self, point: &T) -> bool {
+        &self.start <= point && point < &self.end
+    }
+    fn intersection(&self, other: &Self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        if self.intersects(other) {
+            let start = if self.start >= other.start {
+                self.start.clone()
+            } else {
+                other.start.clone()
+            };
+            let end = if self.end <= other.end {
+                self.end.clone()
+            } else {
+                other.end.clone()
+            };
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+    fn grow_to_contain(&mut self, point: &T)
+    where
+        T: Clone,
+    {
+        if *point < self.start {
+            self.start = point.clone();
+        }
+        if *point >= self.end {
+            self.end = point.clone();
+        }
+    }
+    fn hull(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        let start = if self.start <= other.start {
+            self.start.clone()
+        } else {
+            other.start.clone()
+        };
+        let end = if self.end >= other.end {
+            self.end.clone()
+        } else {
+            other.end.clone()
+        };
+        start..end
+    }
+    fn split_at(&self, mid: &T) -> Option<(Self, Self)>
+    where
+        T: Clone,
+    {
+        if &self.start < mid && mid < &self.end {
+            Some((
+                self.start.clone()..mid.clone(),
+                mid.clone()..self.end.clone(),
+            ))
+        } else {
+            None
+        }
     }
 }
 
@@ -67,6 +160,86 @@ impl<T: Zero + Ord> PositiveAscendingRange<T> {
             Err("End less than start")
         }
     }
+    pub fn contains(&self, point: &T) -> bool {
+        self.0.contains(point)
+    }
+    pub fn intersection(&self, other: &Self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        self.0
+            .intersection(&other.0)
+            .and_then(|r| Self::try_from(r).ok())
+    }
+    pub fn hull(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.hull(&other.0))
+    }
+    pub fn split_at(&self, mid: &T) -> Option<(Self, Self)>
+    where
+        T: Clone,
+    {
+        let (a, b) = self.0.split_at(mid)?;
+        Some((Self::try_from(a).ok()?, Self::try_from(b).ok()?))
+    }
+    /// Grows `self`'s bounds to reach `point`. Fails if `point` is less than zero. Since
+    /// `contains` is half-open, a `point` equal to the new `end` is not itself contained.
+    pub fn grow_to_contain(&mut self, point: &T) -> Result<(), &'static str>
+    where
+        T: Clone,
+    {
+        let mut grown = self.0.clone();
+        grown.grow_to_contain(point);
+        self.0 = Self::try_from(grown)?.0;
+        Ok(())
+    }
+    /// Returns `self` shifted by `delta`, or an error if the shift overflows `T` or drops
+    /// `start` below zero.
+    pub fn checked_translate(&self, delta: T) -> Result<Self, &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        let start = self
+            .0
+            .start
+            .checked_add(&delta)
+            .ok_or("Shift overflowed start")?;
+        let end = self
+            .0
+            .end
+            .checked_add(&delta)
+            .ok_or("Shift overflowed end")?;
+        Self::try_from(start..end)
+    }
+    /// Shifts `self` by `delta` in place, or leaves it unchanged and returns an error if the
+    /// shift overflows `T` or drops `start` below zero.
+    pub fn checked_shift(&mut self, delta: T) -> Result<(), &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        *self = self.checked_translate(delta)?;
+        Ok(())
+    }
+    /// Extends `end` outward by `amount`, or returns an error if doing so overflows `T`.
+    pub fn checked_grow(&mut self, amount: T) -> Result<(), &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        let end = self
+            .0
+            .end
+            .checked_add(&amount)
+            .ok_or("Growing the range overflowed end")?;
+        self.0 = Self::try_from(self.0.start.clone()..end)?.0;
+        Ok(())
+    }
+    /// Reverses direction, producing the equivalent descending range `end..start`.
+    pub fn reverse(self) -> PositiveDescendingRange<T> {
+        PositiveDescendingRange::try_from(self.0.end..self.0.start)
+            .expect("reversing a valid positive ascending range always yields a valid positive descending range")
+    }
 }
 
 /// An ascending range from `start` to `end` (exclusive) where `end` is negative.
@@ -109,6 +282,87 @@ impl<T: Zero + Ord> NegativeAscendingRange<T> {
             Err("End less than start or greater than zero")
         }
     }
+    pub fn contains(&self, point: &T) -> bool {
+        self.0.contains(point)
+    }
+    pub fn intersection(&self, other: &Self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        self.0
+            .intersection(&other.0)
+            .and_then(|r| Self::try_from(r).ok())
+    }
+    pub fn hull(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.hull(&other.0))
+    }
+    pub fn split_at(&self, mid: &T) -> Option<(Self, Self)>
+    where
+        T: Clone,
+    {
+        let (a, b) = self.0.split_at(mid)?;
+        Some((Self::try_from(a).ok()?, Self::try_from(b).ok()?))
+    }
+    /// Grows `self`'s bounds to reach `point`. Fails if `point` is greater than zero. Since
+    /// `contains` is half-open, a `point` equal to the new `end` is not itself contained.
+    pub fn grow_to_contain(&mut self, point: &T) -> Result<(), &'static str>
+    where
+        T: Clone,
+    {
+        let mut grown = self.0.clone();
+        grown.grow_to_contain(point);
+        self.0 = Self::try_from(grown)?.0;
+        Ok(())
+    }
+    /// Returns `self` shifted by `delta`, or an error if the shift overflows `T` or pushes
+    /// `end` above zero.
+    pub fn checked_translate(&self, delta: T) -> Result<Self, &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        let start = self
+            .0
+            .start
+            .checked_add(&delta)
+            .ok_or("Shift overflowed start")?;
+        let end = self
+            .0
+            .end
+            .checked_add(&delta)
+            .ok_or("Shift overflowed end")?;
+        Self::try_from(start..end)
+    }
+    /// Shifts `self` by `delta` in place, or leaves it unchanged and returns an error if the
+    /// shift overflows `T` or pushes `end` above zero.
+    pub fn checked_shift(&mut self, delta: T) -> Result<(), &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        *self = self.checked_translate(delta)?;
+        Ok(())
+    }
+    /// Extends `end` outward by `amount`, or returns an error if doing so overflows `T` or
+    /// pushes `end` above zero.
+    pub fn checked_grow(&mut self, amount: T) -> Result<(), &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        let end = self
+            .0
+            .end
+            .checked_add(&amount)
+            .ok_or("Growing the range overflowed end")?;
+        self.0 = Self::try_from(self.0.start.clone()..end)?.0;
+        Ok(())
+    }
+    /// Reverses direction, producing the equivalent descending range `end..start`.
+    pub fn reverse(self) -> NegativeDescendingRange<T> {
+        NegativeDescendingRange::try_from(self.0.end..self.0.start)
+            .expect("reversing a valid negative ascending range always yields a valid negative descending range")
+    }
 }
 
 /// An ascending range from `start` to `end` (exclusive).
@@ -129,7 +383,7 @@ impl<T: Ord> TryFrom<Range<T>> for AscendingRange<T> {
         }
     }
 }
-impl<T: Zero + Ord> AscendingRange<T> {
+impl<T: Ord> AscendingRange<T> {
     pub fn start(&self) -> &T {
         &self.start
     }
@@ -150,13 +404,803 @@ impl<T: Zero + Ord> AscendingRange<T> {
             Err("End less than start")
         }
     }
+    pub fn contains(&self, point: &T) -> bool {
+        self.0.contains(point)
+    }
+    pub fn intersection(&self, other: &Self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        self.0
+            .intersection(&other.0)
+            .and_then(|r| Self::try_from(r).ok())
+    }
+    pub fn hull(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.hull(&other.0))
+    }
+    pub fn split_at(&self, mid: &T) -> Option<(Self, Self)>
+    where
+        T: Clone,
+    {
+        let (a, b) = self.0.split_at(mid)?;
+        Some((Self::try_from(a).ok()?, Self::try_from(b).ok()?))
+    }
+    /// Grows `self`'s bounds to reach `point`. Since `contains` is half-open, a `point` equal
+    /// to the new `end` is not itself contained.
+    pub fn grow_to_contain(&mut self, point: &T)
+    where
+        T: Clone,
+    {
+        self.0.grow_to_contain(point);
+    }
+    /// Returns `self` shifted by `delta`, or an error if the shift overflows `T`.
+    pub fn checked_translate(&self, delta: T) -> Result<Self, &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        let start = self
+            .0
+            .start
+            .checked_add(&delta)
+            .ok_or("Shift overflowed start")?;
+        let end = self
+            .0
+            .end
+            .checked_add(&delta)
+            .ok_or("Shift overflowed end")?;
+        Self::try_from(start..end)
+    }
+    /// Shifts `self` by `delta` in place, or leaves it unchanged and returns an error if the
+    /// shift overflows `T`.
+    pub fn checked_shift(&mut self, delta: T) -> Result<(), &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        *self = self.checked_translate(delta)?;
+        Ok(())
+    }
+    /// Extends `end` outward by `amount`, or returns an error if doing so overflows `T`.
+    pub fn checked_grow(&mut self, amount: T) -> Result<(), &'static str>
+    where
+        T: Clone + CheckedAdd,
+    {
+        let end = self
+            .0
+            .end
+            .checked_add(&amount)
+            .ok_or("Growing the range overflowed end")?;
+        self.0 = Self::try_from(self.0.start.clone()..end)?.0;
+        Ok(())
+    }
+    /// Reverses direction, producing the equivalent descending range `end..start`.
+    pub fn reverse(self) -> DescendingRange<T> {
+        DescendingRange::try_from(self.0.end..self.0.start)
+            .expect("reversing a valid ascending range always yields a valid descending range")
+    }
+}
+
+/// A descending range from `start` down to `end` (exclusive), where `start >= end`.
+///
+/// The checked counterpart to [`AscendingRange`] for backwards iteration: stack growth
+/// downward, countdowns, or any scan that proceeds from a high bound towards a low one.
+/// Use [`reverse`](Self::reverse) to convert to the equivalent `AscendingRange`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DescendingRange<T: Ord>(Range<T>);
+impl<T: Ord> Deref for DescendingRange<T> {
+    type Target = Range<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T: Ord> TryFrom<Range<T>> for DescendingRange<T> {
+    type Error = &'static str;
+    fn try_from(r: Range<T>) -> Result<Self, Self::Error> {
+        match r.start.cmp(&r.end) {
+            Greater | Equal => Ok(Self(r)),
+            Less => Err(START_LESS_THAN_END_ERR),
+        }
+    }
+}
+impl<T: Ord> DescendingRange<T> {
+    pub fn start(&self) -> &T {
+        &self.0.start
+    }
+    pub fn end(&self) -> &T {
+        &self.0.end
+    }
+    pub fn set_start(&mut self, x: T) -> Result<T, &'static str> {
+        if x >= self.0.end {
+            Ok(mem::replace(&mut self.0.start, x))
+        } else {
+            Err("Start less than end")
+        }
+    }
+    pub fn set_end(&mut self, x: T) -> Result<T, &'static str> {
+        if x <= self.0.start {
+            Ok(mem::replace(&mut self.0.end, x))
+        } else {
+            Err("End greater than start")
+        }
+    }
+    /// `point` lies in `(end, start]`, the descending equivalent of `Range::contains`.
+    pub fn contains(&self, point: &T) -> bool {
+        point <= &self.0.start && point > &self.0.end
+    }
+    /// `self` covers `other`, comparing bounds in descending order.
+    pub fn covers(&self, other: &Self) -> bool {
+        self.0.start >= other.0.start && self.0.end <= other.0.end
+    }
+    /// `self` intersects `other`, comparing bounds in descending order.
+    pub fn intersects(&self, other: &Self) -> bool {
+        other.0.start > self.0.end && self.0.start > other.0.end
+    }
+    /// Reverses direction, producing the equivalent ascending range `end..start`.
+    pub fn reverse(self) -> AscendingRange<T> {
+        AscendingRange::try_from(self.0.end..self.0.start)
+            .expect("reversing a valid descending range always yields a valid ascending range")
+    }
+}
+
+/// A descending range from `start` down to `end` (exclusive) where `end` is positive.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PositiveDescendingRange<T: Zero + Ord>(Range<T>);
+impl<T: Zero + Ord> Deref for PositiveDescendingRange<T> {
+    type Target = Range<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T: Zero + Ord> TryFrom<Range<T>> for PositiveDescendingRange<T> {
+    type Error = &'static str;
+    fn try_from(r: Range<T>) -> Result<Self, Self::Error> {
+        match r.start.cmp(&r.end) {
+            Greater | Equal if r.end >= T::zero() => Ok(Self(r)),
+            Greater | Equal => Err(END_LESS_THAN_ZERO),
+            Less => Err(START_LESS_THAN_END_ERR),
+        }
+    }
+}
+impl<T: Zero + Ord> PositiveDescendingRange<T> {
+    pub fn start(&self) -> &T {
+        &self.0.start
+    }
+    pub fn end(&self) -> &T {
+        &self.0.end
+    }
+    pub fn set_start(&mut self, x: T) -> Result<T, &'static str> {
+        if x >= self.0.end {
+            Ok(mem::replace(&mut self.0.start, x))
+        } else {
+            Err("Start less than end")
+        }
+    }
+    pub fn set_end(&mut self, x: T) -> Result<T, &'static str> {
+        if x <= self.0.start && x >= T::zero() {
+            Ok(mem::replace(&mut self.0.end, x))
+        } else {
+            Err("End greater than start or less than zero")
+        }
+    }
+    pub fn contains(&self, point: &T) -> bool {
+        point <= &self.0.start && point > &self.0.end
+    }
+    pub fn covers(&self, other: &Self) -> bool {
+        self.0.start >= other.0.start && self.0.end <= other.0.end
+    }
+    pub fn intersects(&self, other: &Self) -> bool {
+        other.0.start > self.0.end && self.0.start > other.0.end
+    }
+    /// Reverses direction, producing the equivalent positive ascending range `end..start`.
+    pub fn reverse(self) -> PositiveAscendingRange<T> {
+        PositiveAscendingRange::try_from(self.0.end..self.0.start)
+            .expect("reversing a valid positive descending range always yields a valid positive ascending range")
+    }
+}
+
+/// A descending range from `start` down to `end` (exclusive) where `start` is negative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NegativeDescendingRange<T: Zero + Ord>(Range<T>);
+impl<T: Zero + Ord> Deref for NegativeDescendingRange<T> {
+    type Target = Range<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T: Zero + Ord> TryFrom<Range<T>> for NegativeDescendingRange<T> {
+    type Error = &'static str;
+    fn try_from(r: Range<T>) -> Result<Self, Self::Error> {
+        match r.start.cmp(&r.end) {
+            Greater | Equal if r.start <= T::zero() => Ok(Self(r)),
+            Greater | Equal => Err(START_GREATER_THAN_ZERO),
+            Less => Err(START_LESS_THAN_END_ERR),
+        }
+    }
+}
+impl<T: Zero + Ord> NegativeDescendingRange<T> {
+    pub fn start(&self) -> &T {
+        &self.0.start
+    }
+    pub fn end(&self) -> &T {
+        &self.0.end
+    }
+    pub fn set_start(&mut self, x: T) -> Result<T, &'static str> {
+        if x >= self.0.end && x <= T::zero() {
+            Ok(mem::replace(&mut self.0.start, x))
+        } else {
+            Err("Start less than end or greater than zero")
+        }
+    }
+    pub fn set_end(&mut self, x: T) -> Result<T, &'static str> {
+        if x <= self.0.start {
+            Ok(mem::replace(&mut self.0.end, x))
+        } else {
+            Err("End greater than start")
+        }
+    }
+    pub fn contains(&self, point: &T) -> bool {
+        point <= &self.0.start && point > &self.0.end
+    }
+    pub fn covers(&self, other: &Self) -> bool {
+        self.0.start >= other.0.start && self.0.end <= other.0.end
+    }
+    pub fn intersects(&self, other: &Self) -> bool {
+        other.0.start > self.0.end && self.0.start > other.0.end
+    }
+    /// Reverses direction, producing the equivalent negative ascending range `end..start`.
+    pub fn reverse(self) -> NegativeAscendingRange<T> {
+        NegativeAscendingRange::try_from(self.0.end..self.0.start)
+            .expect("reversing a valid negative descending range always yields a valid negative ascending range")
+    }
+}
+
+/// A set of disjoint [`AscendingRange`]s, kept sorted by `start` and automatically merged on
+/// insertion.
+///
+/// Useful for tracking covered byte ranges, allocated address spaces, or scheduling windows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T: Ord + Clone> {
+    ranges: Vec<AscendingRange<T>>,
+}
+impl<T: Ord + Clone> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+impl<T: Ord + Clone> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The disjoint ranges making up this set, sorted by `start`.
+    pub fn ranges(&self) -> &[AscendingRange<T>] {
+        &self.ranges
+    }
+    /// Inserts `range`, merging it with any existing interval it touches or overlaps.
+    pub fn insert(&mut self, range: AscendingRange<T>) {
+        let mut start = range.start().clone();
+        let mut end = range.end().clone();
+        let mut merged = Vec::with_capacity(self.ranges.len() + 1);
+        let mut i = 0;
+        while i < self.ranges.len() && self.ranges[i].end() < &start {
+            merged.push(self.ranges[i].clone());
+            i += 1;
+        }
+        while i < self.ranges.len() && self.ranges[i].start() <= &end {
+            if self.ranges[i].start() < &start {
+                start = self.ranges[i].start().clone();
+            }
+            if self.ranges[i].end() > &end {
+                end = self.ranges[i].end().clone();
+            }
+            i += 1;
+        }
+        merged.push(
+            AscendingRange::try_from(start..end).expect("merging valid ranges is always valid"),
+        );
+        merged.extend_from_slice(&self.ranges[i..]);
+        self.ranges = merged;
+    }
+    /// Removes `range` from the set, splitting a stored interval in two if `range` punches a
+    /// hole through its middle.
+    pub fn remove(&mut self, range: &AscendingRange<T>) {
+        let mut remaining = Vec::with_capacity(self.ranges.len() + 1);
+        for stored in self.ranges.drain(..) {
+            if !stored.0.intersects(&range.0) {
+                remaining.push(stored);
+                continue;
+            }
+            if stored.start() < range.start() {
+                remaining.push(
+                    AscendingRange::try_from(stored.start().clone()..range.start().clone())
+                        .expect("left remainder of a valid range is always valid"),
+                );
+            }
+            if stored.end() > range.end() {
+                remaining.push(
+                    AscendingRange::try_from(range.end().clone()..stored.end().clone())
+                        .expect("right remainder of a valid range is always valid"),
+                );
+            }
+        }
+        self.ranges = remaining;
+    }
+    /// Whether `point` lies in any of the set's ranges.
+    pub fn contains(&self, point: &T) -> bool {
+        let idx = self.ranges.partition_point(|r| r.start() <= point);
+        idx > 0 && self.ranges[idx - 1].contains(point)
+    }
+    /// Whether some single range in the set fully covers `range`.
+    pub fn covers(&self, range: &AscendingRange<T>) -> bool {
+        let idx = self.ranges.partition_point(|r| r.start() <= range.start());
+        idx > 0 && self.ranges[idx - 1].0.covers(&range.0)
+    }
+}
+
+/// An ascending inclusive range from `start` to `end` (both bounds included).
+///
+/// Unlike [`AscendingRange`] this cannot be stored as a plain `start..end` pair: once
+/// iteration has consumed the element at `end`, advancing `start` past `end` would
+/// overflow when `end == T::MAX`. Instead exhaustion is tracked in its own field, the
+/// same trick `core::ops::RangeInclusive` uses internally.
+#[derive(Debug, Clone)]
+pub struct AscendingRangeInclusive<T: Ord> {
+    start: T,
+    end: T,
+    exhausted: bool,
+}
+impl<T: Ord> PartialEq for AscendingRangeInclusive<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.exhausted == other.exhausted
+    }
+}
+impl<T: Ord> Eq for AscendingRangeInclusive<T> {}
+impl<T: Ord + Hash> Hash for AscendingRangeInclusive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.exhausted.hash(state);
+    }
+}
+impl<T: Ord> TryFrom<RangeInclusive<T>> for AscendingRangeInclusive<T> {
+    type Error = &'static str;
+    fn try_from(r: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = r.into_inner();
+        match start.cmp(&end) {
+            Less | Equal => Ok(Self {
+                start,
+                end,
+                exhausted: false,
+            }),
+            Greater => Err(START_AFTER_END_ERR),
+        }
+    }
+}
+impl<T: Ord> AscendingRangeInclusive<T> {
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+    pub fn set_start(&mut self, x: T) -> Result<T, &'static str> {
+        if x <= self.end {
+            Ok(mem::replace(&mut self.start, x))
+        } else {
+            Err("Start greater than end")
+        }
+    }
+    pub fn set_end(&mut self, x: T) -> Result<T, &'static str> {
+        if x >= self.start {
+            Ok(mem::replace(&mut self.end, x))
+        } else {
+            Err("End less than start")
+        }
+    }
+    /// Whether every element of the range has already been yielded.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+    /// Marks the range as fully consumed, without moving `start` past `end`.
+    pub fn exhaust(&mut self) {
+        self.exhausted = true;
+    }
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end || self.exhausted
+    }
+}
+
+/// An ascending inclusive range from `start` to `end` where `start` is positive.
+#[derive(Debug, Clone)]
+pub struct PositiveAscendingRangeInclusive<T: Zero + Ord> {
+    start: T,
+    end: T,
+    exhausted: bool,
+}
+impl<T: Zero + Ord> PartialEq for PositiveAscendingRangeInclusive<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.exhausted == other.exhausted
+    }
+}
+impl<T: Zero + Ord> Eq for PositiveAscendingRangeInclusive<T> {}
+impl<T: Zero + Ord + Hash> Hash for PositiveAscendingRangeInclusive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.exhausted.hash(state);
+    }
+}
+impl<T: Zero + Ord> TryFrom<RangeInclusive<T>> for PositiveAscendingRangeInclusive<T> {
+    type Error = &'static str;
+    fn try_from(r: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = r.into_inner();
+        match start.cmp(&end) {
+            Less | Equal if start >= T::zero() => Ok(Self {
+                start,
+                end,
+                exhausted: false,
+            }),
+            Less | Equal => Err(START_LESS_THAN_ZERO),
+            Greater => Err(START_AFTER_END_ERR),
+        }
+    }
+}
+impl<T: Zero + Ord> PositiveAscendingRangeInclusive<T> {
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+    pub fn set_start(&mut self, x: T) -> Result<T, &'static str> {
+        if x <= self.end && x >= T::zero() {
+            Ok(mem::replace(&mut self.start, x))
+        } else {
+            Err("Start greater than end or less than zero")
+        }
+    }
+    pub fn set_end(&mut self, x: T) -> Result<T, &'static str> {
+        if x >= self.start {
+            Ok(mem::replace(&mut self.end, x))
+        } else {
+            Err("End less than start")
+        }
+    }
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+    pub fn exhaust(&mut self) {
+        self.exhausted = true;
+    }
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end || self.exhausted
+    }
+}
+
+/// An ascending inclusive range from `start` to `end` where `end` is negative.
+#[derive(Debug, Clone)]
+pub struct NegativeAscendingRangeInclusive<T: Zero + Ord> {
+    start: T,
+    end: T,
+    exhausted: bool,
+}
+impl<T: Zero + Ord> PartialEq for NegativeAscendingRangeInclusive<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.exhausted == other.exhausted
+    }
+}
+impl<T: Zero + Ord> Eq for NegativeAscendingRangeInclusive<T> {}
+impl<T: Zero + Ord + Hash> Hash for NegativeAscendingRangeInclusive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.exhausted.hash(state);
+    }
+}
+impl<T: Zero + Ord> TryFrom<RangeInclusive<T>> for NegativeAscendingRangeInclusive<T> {
+    type Error = &'static str;
+    fn try_from(r: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = r.into_inner();
+        match start.cmp(&end) {
+            Less | Equal if end <= T::zero() => Ok(Self {
+                start,
+                end,
+                exhausted: false,
+            }),
+            Less | Equal => Err(END_GREATER_THAN_ZERO),
+            Greater => Err(START_AFTER_END_ERR),
+        }
+    }
+}
+impl<T: Zero + Ord> NegativeAscendingRangeInclusive<T> {
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+    pub fn set_start(&mut self, x: T) -> Result<T, &'static str> {
+        if x <= self.end {
+            Ok(mem::replace(&mut self.start, x))
+        } else {
+            Err("Start greater than end")
+        }
+    }
+    pub fn set_end(&mut self, x: T) -> Result<T, &'static str> {
+        if x >= self.start && x <= T::zero() {
+            Ok(mem::replace(&mut self.end, x))
+        } else {
+            Err("End less than start or greater than zero")
+        }
+    }
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+    pub fn exhaust(&mut self) {
+        self.exhausted = true;
+    }
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end || self.exhausted
+    }
 }
 
 #[cfg(test)]
+// Many tests here deliberately construct `start..end` literals with `start >= end` to exercise
+// rejection/descending-range validation; these are not actually-empty ranges being iterated.
+#[allow(clippy::reversed_empty_ranges)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         let result = 2 + 2;
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn ascending_range_inclusive_exhaustion_at_max() {
+        let mut r = AscendingRangeInclusive::try_from(u8::MAX..=u8::MAX).unwrap();
+        assert!(!r.is_empty());
+        r.exhaust();
+        assert!(r.is_exhausted());
+        assert!(r.is_empty());
+        // Exhausting never moves `start` past `end`, so no overflow occurs.
+        assert_eq!(*r.start(), u8::MAX);
+        assert_eq!(*r.end(), u8::MAX);
+    }
+
+    #[test]
+    fn ascending_range_inclusive_rejects_start_after_end() {
+        assert!(AscendingRangeInclusive::try_from(2..=1).is_err());
+        assert!(AscendingRangeInclusive::try_from(1..=1).is_ok());
+    }
+
+    #[test]
+    fn ascending_range_inclusive_equality_considers_exhaustion() {
+        let mut a = AscendingRangeInclusive::try_from(0..=5).unwrap();
+        let b = AscendingRangeInclusive::try_from(0..=5).unwrap();
+        assert_eq!(a, b);
+        a.exhaust();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn positive_ascending_range_inclusive_rejects_negative_start() {
+        assert!(PositiveAscendingRangeInclusive::try_from(-1..=5).is_err());
+        assert!(PositiveAscendingRangeInclusive::try_from(0..=5).is_ok());
+    }
+
+    #[test]
+    fn negative_ascending_range_inclusive_rejects_positive_end() {
+        assert!(NegativeAscendingRangeInclusive::try_from(-5..=1).is_err());
+        assert!(NegativeAscendingRangeInclusive::try_from(-5..=0).is_ok());
+    }
+
+    #[test]
+    fn negative_ascending_range_inclusive_set_end_allows_zero() {
+        let mut r = NegativeAscendingRangeInclusive::try_from(-5..=0).unwrap();
+        assert!(r.set_end(0).is_ok());
+        assert_eq!(*r.end(), 0);
+    }
+
+    #[test]
+    fn range_intersects_touching_boundary_does_not_overlap() {
+        // Half-open ranges that merely touch at a boundary do not intersect.
+        assert!(!(0..5).intersects(&(5..10)));
+        assert!((0..5).intersects(&(4..10)));
+    }
+
+    #[test]
+    fn range_contains_is_half_open() {
+        assert!((0..5).contains(&0));
+        assert!(!(0..5).contains(&5));
+    }
+
+    #[test]
+    fn range_intersection_returns_overlap() {
+        assert_eq!((0..10).intersection(&(5..15)), Some(5..10));
+        assert_eq!((0..5).intersection(&(5..10)), None);
+    }
+
+    #[test]
+    fn range_hull_covers_both() {
+        assert_eq!((2..5).hull(&(10..20)), 2..20);
+    }
+
+    #[test]
+    fn range_split_at_requires_strict_interior_point() {
+        assert_eq!((0..10).split_at(&5), Some((0..5, 5..10)));
+        assert_eq!((0..10).split_at(&0), None);
+        assert_eq!((0..10).split_at(&10), None);
+    }
+
+    #[test]
+    fn ascending_range_split_at_preserves_newtype_invariant() {
+        let r = PositiveAscendingRange::try_from(0..10).unwrap();
+        let (a, b) = r.split_at(&5).unwrap();
+        assert_eq!(*a.start(), 0);
+        assert_eq!(*a.end(), 5);
+        assert_eq!(*b.start(), 5);
+        assert_eq!(*b.end(), 10);
+    }
+
+    #[test]
+    fn ascending_range_grow_to_contain_point() {
+        let mut r = AscendingRange::try_from(2..4).unwrap();
+        r.grow_to_contain(&10);
+        assert_eq!(*r.end(), 10);
+        // Values strictly inside the new bound are covered...
+        assert!(r.contains(&9));
+        // ...but per `contains`'s half-open convention, the grown-to point itself is not,
+        // since there is no generic way to compute "one past `point`" for an arbitrary `T`.
+        assert!(!r.contains(&10));
+    }
+
+    #[test]
+    fn checked_translate_rejects_overflow() {
+        let r = AscendingRange::try_from(u8::MAX - 1..u8::MAX).unwrap();
+        assert!(r.checked_translate(1).is_err());
+        assert!(r.checked_translate(0).is_ok());
+    }
+
+    #[test]
+    fn checked_shift_leaves_range_unchanged_on_overflow() {
+        let mut r = AscendingRange::try_from(u8::MAX - 1..u8::MAX).unwrap();
+        let before = r.clone();
+        assert!(r.checked_shift(1).is_err());
+        assert_eq!(r, before);
+    }
+
+    #[test]
+    fn checked_grow_rejects_overflow() {
+        let mut r = AscendingRange::try_from(0u8..u8::MAX).unwrap();
+        assert!(r.checked_grow(1).is_err());
+    }
+
+    #[test]
+    fn positive_ascending_range_checked_translate_rejects_going_negative() {
+        let r = PositiveAscendingRange::try_from(0..5).unwrap();
+        assert!(r.checked_translate(-1).is_err());
+        assert!(r.checked_translate(1).is_ok());
+    }
+
+    #[test]
+    fn negative_ascending_range_checked_translate_rejects_going_positive() {
+        let r = NegativeAscendingRange::try_from(-5..0).unwrap();
+        assert!(r.checked_translate(1).is_err());
+        assert!(r.checked_translate(-1).is_ok());
+    }
+
+    #[test]
+    fn negative_ascending_range_checked_shift_rejects_overflow() {
+        let mut r = NegativeAscendingRange::try_from(i8::MIN..i8::MIN + 1).unwrap();
+        let before = r.clone();
+        assert!(r.checked_shift(-1).is_err());
+        assert_eq!(r, before);
+    }
+
+    #[test]
+    fn negative_ascending_range_checked_grow_rejects_going_positive() {
+        let mut r = NegativeAscendingRange::try_from(-5..-1).unwrap();
+        assert!(r.checked_grow(2).is_err());
+        assert!(r.checked_grow(1).is_ok());
+    }
+
+    #[test]
+    fn range_set_merges_overlapping_inserts() {
+        let mut set = RangeSet::new();
+        set.insert(AscendingRange::try_from(0..5).unwrap());
+        set.insert(AscendingRange::try_from(3..8).unwrap());
+        assert_eq!(set.ranges().len(), 1);
+        assert_eq!(*set.ranges()[0].start(), 0);
+        assert_eq!(*set.ranges()[0].end(), 8);
+    }
+
+    #[test]
+    fn range_set_merges_adjacent_inserts() {
+        let mut set = RangeSet::new();
+        set.insert(AscendingRange::try_from(0..5).unwrap());
+        set.insert(AscendingRange::try_from(5..8).unwrap());
+        assert_eq!(set.ranges().len(), 1);
+        assert_eq!(*set.ranges()[0].end(), 8);
+    }
+
+    #[test]
+    fn range_set_keeps_disjoint_ranges_separate() {
+        let mut set = RangeSet::new();
+        set.insert(AscendingRange::try_from(0..5).unwrap());
+        set.insert(AscendingRange::try_from(10..15).unwrap());
+        assert_eq!(set.ranges().len(), 2);
+    }
+
+    #[test]
+    fn range_set_remove_splits_stored_interval() {
+        let mut set = RangeSet::new();
+        set.insert(AscendingRange::try_from(0..10).unwrap());
+        set.remove(&AscendingRange::try_from(3..6).unwrap());
+        assert_eq!(set.ranges().len(), 2);
+        assert_eq!(*set.ranges()[0].start(), 0);
+        assert_eq!(*set.ranges()[0].end(), 3);
+        assert_eq!(*set.ranges()[1].start(), 6);
+        assert_eq!(*set.ranges()[1].end(), 10);
+    }
+
+    #[test]
+    fn range_set_contains_and_covers() {
+        let mut set = RangeSet::new();
+        set.insert(AscendingRange::try_from(0..10).unwrap());
+        assert!(set.contains(&5));
+        assert!(!set.contains(&10));
+        assert!(set.covers(&AscendingRange::try_from(2..8).unwrap()));
+        assert!(!set.covers(&AscendingRange::try_from(8..12).unwrap()));
+    }
+
+    #[test]
+    fn descending_range_rejects_start_less_than_end() {
+        assert!(DescendingRange::try_from(1..5).is_err());
+        assert!(DescendingRange::try_from(5..1).is_ok());
+        assert!(DescendingRange::try_from(3..3).is_ok());
+    }
+
+    #[test]
+    fn descending_range_reverse_round_trips_with_ascending() {
+        let ascending = AscendingRange::try_from(1..5).unwrap();
+        let descending = ascending.clone().reverse();
+        assert_eq!(*descending.start(), 5);
+        assert_eq!(*descending.end(), 1);
+        assert_eq!(descending.reverse(), ascending);
+    }
+
+    #[test]
+    fn descending_range_contains_and_intersects() {
+        let r = DescendingRange::try_from(10..0).unwrap();
+        assert!(r.contains(&10));
+        assert!(!r.contains(&0));
+        let other = DescendingRange::try_from(15..10).unwrap();
+        assert!(!r.intersects(&other));
+        let overlapping = DescendingRange::try_from(15..5).unwrap();
+        assert!(r.intersects(&overlapping));
+    }
+
+    #[test]
+    fn positive_descending_range_rejects_negative_end() {
+        assert!(PositiveDescendingRange::try_from(5..-1).is_err());
+        assert!(PositiveDescendingRange::try_from(5..0).is_ok());
+    }
+
+    #[test]
+    fn negative_descending_range_rejects_positive_start() {
+        assert!(NegativeDescendingRange::try_from(1..-5).is_err());
+        assert!(NegativeDescendingRange::try_from(0..-5).is_ok());
+    }
+
+    #[test]
+    fn positive_descending_range_reverse_round_trips() {
+        let descending = PositiveDescendingRange::try_from(5..0).unwrap();
+        let ascending = descending.clone().reverse();
+        assert_eq!(*ascending.start(), 0);
+        assert_eq!(*ascending.end(), 5);
+        assert_eq!(ascending.reverse(), descending);
+    }
 }